@@ -40,11 +40,14 @@ class Settings:
     ----------
     jobs
         The number of jobs to use for building.
+    parallel_downloads
+        The number of sources to download concurrently.
     editor
         The editor to use for opening files.
     """
 
     jobs: int
+    parallel_downloads: int
     editor: str
 
     def __init__(self, raw_config: Dict[str, Any]) -> None:
@@ -59,6 +62,7 @@ class Settings:
 
         settings = raw_config["settings"]
         self.jobs = settings.get("jobs", cpu_count())
+        self.parallel_downloads = settings.get("parallel_downloads", 4)
 
         # Loading order:
         # 1. `editor` config file value.
@@ -80,9 +84,12 @@ class Config:
         The raw config parsed dictionary.
     settings
         Facade for the config file settings.
+    aliases
+        Mapping of short names to the packages they stand for.
     """
 
     settings: Settings
+    aliases: Dict[str, str]
     raw_config: Dict[str, Any]
 
     def __init__(
@@ -106,3 +113,21 @@ class Config:
         self.raw_config = raw_config
 
         self.settings = Settings(raw_config)  # type: ignore[unknown-type]
+        self.aliases = raw_config.get("aliases", {})
+
+    def resolve_alias(self, name: str) -> str:
+        """
+        Resolve a package name through the aliases table.
+
+        Parameters
+        ----------
+        name
+            The name to resolve.
+
+        Returns
+        -------
+        str
+            The package the alias maps to, or `name` if it is not an alias.
+        """
+
+        return self.aliases.get(name, name)
@@ -83,6 +83,39 @@ def test_raw_config(config_file: Path, tmp_path: Path) -> None:
     }
 
 
+def test_aliases(config_file: Path) -> None:
+    """
+    Test that aliases are parsed and resolved correctly.
+
+    Parameters
+    ----------
+    config_file
+        Path to the config file. (fixture)
+    """
+
+    aliases_toml = """
+                   [settings]
+                   jobs = 10
+
+                   [aliases]
+                   code = "vscode-deb"
+                   """
+
+    config_file.write_text(aliases_toml)
+    config = libpacstall_config.Config(config_file=config_file)
+
+    assert config.aliases == {"code": "vscode-deb"}
+    assert config.resolve_alias("code") == "vscode-deb"
+    assert config.resolve_alias("neofetch") == "neofetch"
+
+    # Test config with no aliases.
+    config_file.write_text(boilerplate_toml)
+    config = libpacstall_config.Config(config_file=config_file)
+
+    assert config.aliases == {}
+    assert config.resolve_alias("code") == "code"
+
+
 class TestSettings:
     def test_jobs(self, config_file: Path) -> None:
         """
@@ -110,6 +143,31 @@ class TestSettings:
             == cpu_count()
         )
 
+    def test_parallel_downloads(self, config_file: Path) -> None:
+        """
+        Test that the parallel downloads setting is correctly parsed.
+
+        Parameters
+        ----------
+        config_file
+            Path to the config file. (fixture)
+        """
+
+        # Test config with parallel downloads.
+        parallel_downloads_toml = """
+                                  [settings]
+                                  parallel_downloads = 8
+                                  """
+
+        config_file.write_text(parallel_downloads_toml)
+        settings = libpacstall_config.Config(config_file=config_file).settings
+        assert settings.parallel_downloads == 8
+
+        # Test boilerplate config, which has no parallel downloads.
+        config_file.write_text(boilerplate_toml)
+        settings = libpacstall_config.Config(config_file=config_file).settings
+        assert settings.parallel_downloads == 4
+
     def test_editor(self, config_file: Path, monkeypatch: pytest.MonkeyPatch) -> None:
         """
         Test that the editor is set to the correct value.